@@ -98,6 +98,53 @@ impl<'a> ValueSet<'a> {
         self.values.visit(&mut visitor);
         visitor.res
     }
+
+    /// Looks up the value at `path`, a dot-separated sequence of field
+    /// names, returning `None` if any segment of the path does not name a
+    /// field.
+    ///
+    /// The first segment is looked up among this `ValueSet`'s own fields;
+    /// each subsequent segment is looked up on the [`Value::Structable`]
+    /// found at the previous segment. For example, `"request.status"` looks
+    /// up a `request` field, then a `status` field on its value.
+    pub fn get(&self, path: &str) -> Option<Value<'_>> {
+        let mut segments = path.split('.');
+        let mut current = get_named_value(self.values, segments.next()?)?;
+        for segment in segments {
+            let Value::Structable(structable) = current else {
+                return None;
+            };
+            current = get_named_value(structable, segment)?;
+        }
+        Some(current)
+    }
+}
+
+/// Looks up the value of the field named `name` among `values`'s top-level
+/// fields.
+fn get_named_value<'v>(values: &'v dyn Structable, name: &str) -> Option<Value<'v>> {
+    struct GetVisitor<'n, 'v> {
+        name: &'n str,
+        found: Option<Value<'v>>,
+    }
+
+    impl<'n, 'v> Visit for GetVisitor<'n, 'v> {
+        fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+            if self.found.is_none() {
+                self.found = named_values.get_by_name(self.name);
+            }
+        }
+
+        fn visit_value(&mut self, value: Value<'_>) {
+            if let Value::Structable(v) = value {
+                v.visit(self)
+            }
+        }
+    }
+
+    let mut visitor = GetVisitor { name, found: None };
+    values.visit(&mut visitor);
+    visitor.found
 }
 
 struct IsEmptyVisitor {
@@ -353,4 +400,56 @@ mod test {
         valueset.visit(&mut visitor);
         assert_eq!(visitor.result, format!("{}", err_struct.err));
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn get_top_level_field() {
+        #[derive(Valuable)]
+        struct MyStruct {
+            foo: u32,
+            bar: u32,
+            baz: u32,
+        }
+
+        let my_struct = MyStruct {
+            foo: 1,
+            bar: 2,
+            baz: 3,
+        };
+
+        let valueset = ValueSet {
+            values: &my_struct,
+            callsite: crate::identify_callsite!(&TEST_CALLSITE_1),
+        };
+        assert!(matches!(valueset.get("bar"), Some(Value::U32(2))));
+        assert!(valueset.get("quux").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn get_nested_field() {
+        #[derive(Valuable)]
+        struct Inner {
+            baz: u32,
+        }
+
+        #[derive(Valuable)]
+        struct MyStruct {
+            foo: u32,
+            bar: Inner,
+        }
+
+        let my_struct = MyStruct {
+            foo: 1,
+            bar: Inner { baz: 3 },
+        };
+
+        let valueset = ValueSet {
+            values: &my_struct,
+            callsite: crate::identify_callsite!(&TEST_CALLSITE_1),
+        };
+        assert!(matches!(valueset.get("bar.baz"), Some(Value::U32(3))));
+        assert!(valueset.get("bar.quux").is_none());
+        assert!(valueset.get("foo.baz").is_none());
+    }
 }
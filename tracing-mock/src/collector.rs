@@ -2,7 +2,7 @@
 use crate::valuable::NamedValues_;
 
 use super::{
-    event::MockEvent,
+    event::{Cardinality, MockEvent},
     span::{MockSpan, NewSpan},
 };
 use std::{
@@ -17,12 +17,13 @@ use std::{
 use tracing::{
     collect::Interest,
     level_filters::LevelFilter,
+    metric::Metric,
     span::{Attributes, Id},
-    Collect, Event, Metadata,
+    Collect, Event, Level, Metadata,
 };
 use valuable::{NamedValues, Value};
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug)]
 pub enum Expect<'a> {
     Event(MockEvent<'a>),
     FollowsFrom {
@@ -33,45 +34,195 @@ pub enum Expect<'a> {
     Exit(MockSpan),
     CloneSpan(MockSpan),
     DropSpan(MockSpan),
-    Visit(MockSpan, NamedValues_<'a>),
-    NewSpan(NewSpan<'a>),
+    /// The third field holds predicate-based expectations for individual
+    /// fields, added via [`MockCollector::record_field_matching`] and
+    /// checked in addition to the exact `NamedValues_` in the second field.
+    /// See [`FieldPredicate`].
+    Visit(MockSpan, NamedValues_<'a>, Vec<(String, FieldPredicate<'a>)>),
+    /// The second field is the `Id` `new_span` should return when this
+    /// expectation matches, if one was scripted with
+    /// [`MockCollector::returning_id`], instead of a freshly minted one.
+    NewSpan(NewSpan<'a>, Option<Id>),
     Nothing,
 }
 
+// `FieldPredicate` holds a `Box<dyn Fn>`, which has no meaningful notion of
+// equality, so `Expect` can't derive `PartialEq`/`Eq`: the predicates
+// attached to a `Visit` are a runtime check performed in `Running::record`,
+// not a value compared against another `Expect`. `MockEvent`'s own
+// `PartialEq` impl (in `event.rs`) excludes its predicate- and
+// sequence-bearing fields for the same reason.
+impl<'a> PartialEq for Expect<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Expect::Event(a), Expect::Event(b)) => a == b,
+            (
+                Expect::FollowsFrom {
+                    consequence: ca,
+                    cause: ua,
+                },
+                Expect::FollowsFrom {
+                    consequence: cb,
+                    cause: ub,
+                },
+            ) => ca == cb && ua == ub,
+            (Expect::Enter(a), Expect::Enter(b)) => a == b,
+            (Expect::Exit(a), Expect::Exit(b)) => a == b,
+            (Expect::CloneSpan(a), Expect::CloneSpan(b)) => a == b,
+            (Expect::DropSpan(a), Expect::DropSpan(b)) => a == b,
+            (Expect::Visit(sa, va, _), Expect::Visit(sb, vb, _)) => sa == sb && va == vb,
+            (Expect::NewSpan(a, ia), Expect::NewSpan(b, ib)) => a == b && ia == ib,
+            (Expect::Nothing, Expect::Nothing) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Eq for Expect<'a> {}
+
+/// A closure-based expectation for a single field recorded via
+/// [`MockCollector::record`]/[`MockCollector::record_field_matching`].
+///
+/// Unlike the literal equality check `record` performs against its
+/// `NamedValues_`, a `FieldPredicate` lets a test assert something an exact
+/// value can't express, e.g. that a numeric field falls within a range or a
+/// string contains a substring. Returning `Err(message)` fails the match
+/// with that message (surfaced through [`Expect::bad`]) instead of a
+/// generic "expected X, got Y".
+pub struct FieldPredicate<'a>(Box<dyn Fn(&Value<'_>) -> Result<(), String> + 'a>);
+
+impl<'a> FieldPredicate<'a> {
+    pub fn new(predicate: impl Fn(&Value<'_>) -> Result<(), String> + 'a) -> Self {
+        Self(Box::new(predicate))
+    }
+}
+
+impl<'a> fmt::Debug for FieldPredicate<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FieldPredicate(..)")
+    }
+}
+
 struct SpanState {
     name: &'static str,
     refs: usize,
     meta: &'static Metadata<'static>,
 }
 
+/// A call-count expectation registered via `MockEvent::times`/`never`/
+/// `at_least`/`at_most`.
+///
+/// Unlike the strict FIFO `expected` queue, these are checked against
+/// *every* observed event regardless of position, so tests can assert
+/// things like "this warning fires at least once among other traffic".
+pub struct CountExpectation<'a> {
+    event: MockEvent<'a>,
+    seen: AtomicUsize,
+}
+
+/// A call-count expectation for any `Expect` variant, registered via
+/// [`MockCollector::times`]/`never`/`at_least`/`at_most`.
+///
+/// This plays the same role as [`CountExpectation`] does for a [`MockEvent`]
+/// built with its own cardinality, but wraps whatever expectation was last
+/// added to the collector: entering, exiting, cloning, or dropping a span,
+/// recording fields, creating a new span, or an event added without a
+/// cardinality of its own.
+/// It's checked against *every* observed notification regardless of its
+/// position in the strict FIFO `expected` queue.
+pub struct RepeatExpectation<'a> {
+    expect: Expect<'a>,
+    cardinality: Cardinality,
+    seen: AtomicUsize,
+}
+
+/// Coarse tallies of every notification `Running` has observed, kept
+/// alongside (not instead of) the scripted `expected`/`counted`/`repeated`
+/// queues so tests can assert things like "no ERRORs were logged" or "no
+/// span refs leaked" without scripting each individual notification.
+///
+/// Shared between `Running` and `MockHandle` the same way the expectation
+/// queues are: both hold an `Arc<Mutex<Tallies>>` pointing at the same data.
+#[derive(Default)]
+pub struct Tallies {
+    /// Number of events observed at each `Level` that was actually seen.
+    events_by_level: HashMap<Level, u64>,
+    /// Number of events observed for each target that was actually seen.
+    events_by_target: HashMap<&'static str, u64>,
+    /// Total number of spans created via `new_span`.
+    spans_created: u64,
+    /// Number of spans currently entered (on the `current` stack).
+    current_depth: usize,
+    /// The highest `current_depth` has ever reached.
+    max_depth: usize,
+    /// Total number of `clone_span` calls observed.
+    clones: u64,
+    /// Total number of `drop_span` calls observed.
+    drops: u64,
+}
+
 struct Running<'a, F: Fn(&Metadata<'_>) -> bool> {
     spans: Mutex<HashMap<Id, SpanState>>,
     expected: Arc<Mutex<VecDeque<Expect<'a>>>>,
+    counted: Arc<Mutex<Vec<CountExpectation<'a>>>>,
+    repeated: Arc<Mutex<Vec<RepeatExpectation<'a>>>>,
     current: Mutex<Vec<Id>>,
     ids: AtomicUsize,
+    /// Monotonically increasing count of observed events, used to check
+    /// `Sequence`-constrained expectations' relative ordering.
+    observation_index: AtomicUsize,
     max_level: Option<LevelFilter>,
     filter: F,
     name: String,
+    /// When `true`, an incoming notification is matched against *any* still
+    /// pending expectation in `expected`, not only the one at the front of
+    /// the queue. See [`MockCollector::unordered`].
+    unordered: bool,
+    /// A scripted sequence of `Interest` values `register_callsite` should
+    /// return, consumed in order. See [`MockCollector::returning_interest`].
+    interests: Mutex<VecDeque<Interest>>,
+    /// Per-callsite overrides for `enabled`, keyed by callsite name. See
+    /// [`MockCollector::returning_enabled_for`].
+    enabled_overrides: HashMap<String, bool>,
+    /// Tallies exposed read-only through `MockHandle`'s metrics
+    /// accessors. See [`Tallies`].
+    metrics: Arc<Mutex<Tallies>>,
 }
 
 pub struct MockCollector<'a, F: Fn(&Metadata<'_>) -> bool> {
     expected: VecDeque<Expect<'a>>,
+    counted: Vec<MockEvent<'a>>,
+    repeated: Vec<(Expect<'a>, Cardinality)>,
     max_level: Option<LevelFilter>,
     filter: F,
     name: String,
+    unordered: bool,
+    interests: VecDeque<Interest>,
+    enabled_overrides: HashMap<String, bool>,
 }
 
-pub struct MockHandle<'a>(Arc<Mutex<VecDeque<Expect<'a>>>>, String);
+pub struct MockHandle<'a>(
+    Arc<Mutex<VecDeque<Expect<'a>>>>,
+    Arc<Mutex<Vec<CountExpectation<'a>>>>,
+    Arc<Mutex<Vec<RepeatExpectation<'a>>>>,
+    String,
+    Arc<Mutex<Tallies>>,
+);
 
 pub fn mock() -> MockCollector<'static, fn(&Metadata<'_>) -> bool> {
     MockCollector {
         expected: VecDeque::new(),
+        counted: Vec::new(),
+        repeated: Vec::new(),
         filter: (|_: &Metadata<'_>| true) as for<'r, 's> fn(&'r Metadata<'s>) -> _,
         max_level: None,
         name: thread::current()
             .name()
             .unwrap_or("mock_subscriber")
             .to_string(),
+        unordered: false,
+        interests: VecDeque::new(),
+        enabled_overrides: HashMap::new(),
     }
 }
 
@@ -111,7 +262,11 @@ where
     }
 
     pub fn event(mut self, event: MockEvent<'a>) -> Self {
-        self.expected.push_back(Expect::Event(event));
+        if event.expected_count == Default::default() && event.sequence.is_none() {
+            self.expected.push_back(Expect::Event(event));
+        } else {
+            self.counted.push(event);
+        }
         self
     }
 
@@ -138,7 +293,31 @@ where
 
     pub fn record(mut self, span: MockSpan, fields: NamedValues<'a>) -> Self
     {
-        self.expected.push_back(Expect::Visit(span, NamedValues_::new(fields)));
+        self.expected
+            .push_back(Expect::Visit(span, NamedValues_::new(fields), Vec::new()));
+        self
+    }
+
+    /// Adds a predicate-based expectation for field `name` to the most
+    /// recently added `record` expectation, checked in addition to the
+    /// exact field values passed to `record`.
+    ///
+    /// Must be called immediately after `.record(span, fields)`. The
+    /// predicate receives the field's observed value and returns
+    /// `Err(message)` instead of `Ok(())` to fail the match with a custom
+    /// message, in place of the generic "expected X, got Y" an exact
+    /// equality check would produce.
+    pub fn record_field_matching(
+        mut self,
+        name: impl Into<String>,
+        predicate: impl Fn(&Value<'_>) -> Result<(), String> + 'a,
+    ) -> Self {
+        match self.expected.back_mut() {
+            Some(Expect::Visit(_, _, matchers)) => {
+                matchers.push((name.into(), FieldPredicate::new(predicate)))
+            }
+            _ => panic!("record_field_matching must follow a record expectation"),
+        }
         self
     }
 
@@ -146,7 +325,40 @@ where
     where
         I: Into<NewSpan<'a>>,
     {
-        self.expected.push_back(Expect::NewSpan(new_span.into()));
+        self.expected.push_back(Expect::NewSpan(new_span.into(), None));
+        self
+    }
+
+    /// Scripts the `Id` that `new_span` should return when it matches the
+    /// `NewSpan` expectation most recently added to this collector, instead
+    /// of minting a fresh sequential one.
+    ///
+    /// This is useful for tests that need a deterministic `Id` to assert
+    /// against later calls (`enter`, `exit`, `record`, ...), or that compare
+    /// `Id`s observed across multiple collectors.
+    pub fn returning_id(mut self, id: Id) -> Self {
+        match self.expected.back_mut() {
+            Some(Expect::NewSpan(_, scripted)) => *scripted = Some(id),
+            _ => panic!("returning_id must follow a new_span expectation"),
+        }
+        self
+    }
+
+    /// Scripts the next `Interest` value `register_callsite` should return.
+    ///
+    /// Each call queues one value; `register_callsite` consumes them in the
+    /// order they were scripted, falling back to the default
+    /// `always()`/`never()` behavior (based on `enabled`) once the queue is
+    /// empty.
+    pub fn returning_interest(mut self, interest: Interest) -> Self {
+        self.interests.push_back(interest);
+        self
+    }
+
+    /// Overrides the result of `enabled` for the callsite named `name`,
+    /// regardless of what the collector's filter would otherwise return.
+    pub fn returning_enabled_for(mut self, name: impl Into<String>, enabled: bool) -> Self {
+        self.enabled_overrides.insert(name.into(), enabled);
         self
     }
 
@@ -156,9 +368,14 @@ where
     {
         MockCollector {
             expected: self.expected,
+            counted: self.counted,
+            repeated: self.repeated,
             filter,
             max_level: self.max_level,
             name: self.name,
+            unordered: self.unordered,
+            interests: self.interests,
+            enabled_overrides: self.enabled_overrides,
         }
     }
 
@@ -169,6 +386,82 @@ where
         }
     }
 
+    /// Puts this collector in unordered mode.
+    ///
+    /// By default, expectations must be satisfied in the order they were
+    /// added: every callback is checked against the expectation at the
+    /// front of the queue. That makes `MockCollector` unusable for
+    /// multi-threaded tests, where span entry/exit and event ordering is
+    /// non-deterministic. In unordered mode, an incoming notification is
+    /// matched against *any* still-pending expectation instead: the first
+    /// one whose matcher (name/fields/parent) accepts it is removed from
+    /// the set. For events, `enter`, `exit`, and `record_follows_from`, the
+    /// collector panics if expectations are still pending but none of them
+    /// accepts the notification; `new_span`, `record`, `clone_span`, and
+    /// `drop_span` notifications that match nothing are ignored.
+    pub fn unordered(mut self) -> Self {
+        self.unordered = true;
+        self
+    }
+
+    /// Moves the most recently added expectation out of the strict FIFO
+    /// queue and into the always-checked repeat-count pool, with the given
+    /// `cardinality`.
+    ///
+    /// Must be called immediately after the expectation it modifies (e.g.
+    /// `.enter(span).times(2)`). A [`MockEvent`] built with
+    /// [`MockEvent::times`] and friends is routed to `counted` rather than
+    /// `expected` when it's added, so calling this right after one does not
+    /// modify that event: it moves whatever expectation is last in the
+    /// `expected` queue instead, and panics only if that queue is empty.
+    fn repeat_last(&mut self, cardinality: Cardinality) {
+        let expect = self.expected.pop_back().unwrap_or_else(|| {
+            assert!(
+                self.counted.is_empty(),
+                "times/never/at_least/at_most must follow an expectation, but the \
+                 most recently added expectation was a `MockEvent` built with its \
+                 own `.times()`/`.never()`/`.at_least()`/`.at_most()`, which routes \
+                 it to the always-checked `counted` pool instead of the `expected` \
+                 queue this method modifies. Set the cardinality in one place, \
+                 either on the `MockEvent` or on the collector expectation, not both."
+            );
+            panic!("times/never/at_least/at_most must follow an expectation");
+        });
+        self.repeated.push((expect, cardinality));
+    }
+
+    /// Expects the most recently added expectation to be satisfied exactly
+    /// `n` times, rather than the default of once.
+    pub fn times(mut self, n: usize) -> Self {
+        self.repeat_last(Cardinality {
+            min: n,
+            max: Some(n),
+        });
+        self
+    }
+
+    /// Expects the most recently added expectation to never be satisfied.
+    pub fn never(self) -> Self {
+        self.times(0)
+    }
+
+    /// Expects the most recently added expectation to be satisfied at least
+    /// `n` times.
+    pub fn at_least(mut self, n: usize) -> Self {
+        self.repeat_last(Cardinality { min: n, max: None });
+        self
+    }
+
+    /// Expects the most recently added expectation to be satisfied at most
+    /// `n` times.
+    pub fn at_most(mut self, n: usize) -> Self {
+        self.repeat_last(Cardinality {
+            min: 0,
+            max: Some(n),
+        });
+        self
+    }
+
     // pub fn run(self) -> impl Collect {
     //     let (collector, _) = self.run_with_handle();
     //     collector
@@ -179,33 +472,141 @@ where
         'a: 'static
     {
         let expected = Arc::new(Mutex::new(self.expected));
-        let handle = MockHandle(expected.clone(), self.name.clone());
+        let counted = Arc::new(Mutex::new(
+            self.counted
+                .into_iter()
+                .map(|event| CountExpectation {
+                    event,
+                    seen: AtomicUsize::new(0),
+                })
+                .collect(),
+        ));
+        let repeated = Arc::new(Mutex::new(
+            self.repeated
+                .into_iter()
+                .map(|(expect, cardinality)| RepeatExpectation {
+                    expect,
+                    cardinality,
+                    seen: AtomicUsize::new(0),
+                })
+                .collect(),
+        ));
+        let metrics = Arc::new(Mutex::new(Tallies::default()));
+        let handle = MockHandle(
+            expected.clone(),
+            counted.clone(),
+            repeated.clone(),
+            self.name.clone(),
+            metrics.clone(),
+        );
         let collector = Running {
             spans: Mutex::new(HashMap::new()),
             expected,
+            counted,
+            repeated,
             current: Mutex::new(Vec::new()),
             ids: AtomicUsize::new(1),
+            observation_index: AtomicUsize::new(0),
             filter: self.filter,
             max_level: self.max_level,
             name: self.name,
+            unordered: self.unordered,
+            interests: Mutex::new(self.interests),
+            enabled_overrides: self.enabled_overrides,
+            metrics,
         };
         (collector, handle)
     }
 }
 
+impl<F> Running<'static, F>
+where
+    F: Fn(&Metadata<'_>) -> bool + 'static,
+{
+    /// Removes and returns the next expectation that should be checked
+    /// against the current notification, if any.
+    ///
+    /// In ordered mode (the default), the front of the queue is only
+    /// popped if it actually `matches` -- mirroring the "soft" conditional
+    /// pop that `new_span`/`clone_span`/`drop_span`/`record` already use.
+    /// This matters because cardinality and sequence expectations (the
+    /// `counted`/`repeated` side pools) are checked independently of
+    /// `expected` and never occupy a slot in it; without the conditional
+    /// pop, the very next notification after one of those would wrongly
+    /// consume and get checked against whatever unrelated expectation
+    /// happens to be at the front of the queue. Returning `None` here just
+    /// means this notification wasn't the strict queue's concern; any
+    /// expectation left behind is still caught by
+    /// [`MockHandle::assert_finished`] at the end of the test. In unordered
+    /// mode, the first pending expectation accepted by `matches` is removed
+    /// instead; if the queue is non-empty but nothing matches,
+    /// `Expect::Nothing` is returned as a sentinel so callers' existing
+    /// "anything else is unexpected" arm reports it.
+    fn take_expectation(&self, matches: impl Fn(&Expect<'static>) -> bool) -> Option<Expect<'static>> {
+        let mut expected = self.expected.lock().unwrap();
+        if expected.is_empty() {
+            return None;
+        }
+        if self.unordered {
+            match expected.iter().position(|e| matches(e)) {
+                Some(pos) => expected.remove(pos),
+                None => Some(Expect::Nothing),
+            }
+        } else {
+            match expected.front() {
+                Some(e) if matches(e) => expected.pop_front(),
+                _ => None,
+            }
+        }
+    }
+
+    /// Checks the current notification against every still-live expectation
+    /// in the generalized `repeated` pool, incrementing its match count and
+    /// asserting any upper bound eagerly.
+    ///
+    /// Unlike `take_expectation`, this never touches the strict FIFO
+    /// `expected` queue; it's a side channel, mirroring how `counted` works
+    /// for [`MockEvent`] call-count expectations.
+    fn record_repeat_match(&self, matches: impl Fn(&Expect<'static>) -> bool) {
+        for expectation in self.repeated.lock().unwrap().iter() {
+            if matches(&expectation.expect) {
+                let seen = expectation.seen.fetch_add(1, Ordering::SeqCst) + 1;
+                if let Some(max) = expectation.cardinality.max {
+                    assert!(
+                        seen <= max,
+                        "[{}] {:?} was expected at most {} time(s), but was recorded {} time(s)",
+                        self.name,
+                        expectation.expect,
+                        max,
+                        seen
+                    );
+                }
+            }
+        }
+    }
+}
+
 impl<F> Collect for Running<'static, F>
 where
     F: Fn(&Metadata<'_>) -> bool + 'static,
 {
     fn enabled(&self, meta: &Metadata<'_>) -> bool {
         println!("[{}] enabled: {:#?}", self.name, meta);
-        let enabled = (self.filter)(meta);
+        let enabled = self
+            .enabled_overrides
+            .get(meta.name())
+            .copied()
+            .unwrap_or_else(|| (self.filter)(meta));
         println!("[{}] enabled -> {}", self.name, enabled);
         enabled
     }
 
     fn register_callsite(&self, meta: &'static Metadata<'static>) -> Interest {
         println!("[{}] register_callsite: {:#?}", self.name, meta);
+        if let Some(interest) = self.interests.lock().unwrap().pop_front() {
+            println!("[{}] register_callsite -> scripted {:?}", self.name, interest);
+            return interest;
+        }
         if self.enabled(meta) {
             Interest::always()
         } else {
@@ -218,7 +619,6 @@ where
 
     fn record(&self, id: &Id, values: NamedValues<'_>) {
         let spans = self.spans.lock().unwrap();
-        let mut expected = self.expected.lock().unwrap();
         let span = spans
             .get(id)
             .unwrap_or_else(|| panic!("[{}] no span for ID {:?}", self.name, id));
@@ -226,16 +626,35 @@ where
             "[{}] record: {}; id={:?}; values={:?};",
             self.name, span.name, id, values
         );
-        let was_expected = matches!(expected.front(), Some(Expect::Visit(_, _)));
-        if was_expected {
-            if let Expect::Visit(expected_span, expected_values) = expected.pop_front().unwrap()
+        let mut expected = self.expected.lock().unwrap();
+        let matches = |e: &Expect<'static>| {
+            matches!(e, Expect::Visit(s, _, _) if s.name().map_or(true, |n| n == span.name))
+        };
+        self.record_repeat_match(matches);
+        let pos = if self.unordered {
+            expected.iter().position(matches)
+        } else {
+            match expected.front() {
+                Some(e) if matches(e) => Some(0),
+                _ => None,
+            }
+        };
+        if let Some(pos) = pos {
+            if let Some(Expect::Visit(expected_span, expected_values, field_matchers)) =
+                expected.remove(pos)
             {
                 if let Some(name) = expected_span.name() {
                     assert_eq!(name, span.name);
                 }
                 let context = format!("span {}: ", span.name);
                 for (expected_field, expected_value) in expected_values.0.lock().unwrap().iter() {
-                    let value = values.get_by_name(expected_field.name()).unwrap();
+                    let value = values.get_by_name(expected_field.name()).unwrap_or_else(|| {
+                        panic!(
+                            "{}expected field `{}` to be present, but it was absent",
+                            context,
+                            expected_field.name()
+                        )
+                    });
                     match (value, expected_value) {
                         (Value::Bool(v), Value::Bool(e)) => assert_eq!(v, e),
                         (Value::Char(v), Value::Char(e)) => assert_eq!(v, e),
@@ -254,17 +673,49 @@ where
                         (Value::U64(v), Value::U64(e)) => assert_eq!(v, e),
                         (Value::U128(v), Value::U128(e)) => assert_eq!(v, e),
                         (Value::Usize(v), Value::Usize(e)) => assert_eq!(v, e),
-                        (Value::Error(_), Value::Error(_)) => unimplemented!(),
-                        (Value::Listable(_), Value::Listable(_)) => unimplemented!(),
-                        (Value::Mappable(_), Value::Mappable(_)) => unimplemented!(),
-                        (Value::Structable(_), Value::Structable(_)) => unimplemented!(),
-                        (Value::Enumerable(_), Value::Enumerable(_)) => unimplemented!(),
-                        (Value::Tuplable(_), Value::Tuplable(_)) => unimplemented!(),
                         (Value::Unit, Value::Unit) => (),
-                        _ => unimplemented!(),
+                        // Compound values (and errors, which only expose a
+                        // `Display`/`Debug` rendering) are compared by
+                        // converting both sides to an owned, structurally
+                        // comparable snapshot -- see `valuable::values_equal`.
+                        (Value::Error(_), Value::Error(_))
+                        | (Value::Listable(_), Value::Listable(_))
+                        | (Value::Mappable(_), Value::Mappable(_))
+                        | (Value::Structable(_), Value::Structable(_))
+                        | (Value::Enumerable(_), Value::Enumerable(_))
+                        | (Value::Tuplable(_), Value::Tuplable(_)) => assert!(
+                            crate::valuable::values_equal(value, expected_value),
+                            "{}expected field `{}` to equal {:?}, but it was {:?}",
+                            context,
+                            expected_field.name(),
+                            expected_value,
+                            value
+                        ),
+                        _ => panic!(
+                            "{}expected field `{}` to equal {:?}, but it was {:?}",
+                            context,
+                            expected_field.name(),
+                            expected_value,
+                            value
+                        ),
                     }
                 }
 
+                for (field_name, predicate) in &field_matchers {
+                    let value = values.get_by_name(field_name).unwrap_or_else(|| {
+                        panic!(
+                            "{}expected field `{}` to be present, but it was absent",
+                            context, field_name
+                        )
+                    });
+                    if let Err(message) = (predicate.0)(&value) {
+                        Expect::Visit(expected_span.clone(), expected_values.clone(), Vec::new())
+                            .bad(
+                                &self.name,
+                                format_args!("field `{}`: {}", field_name, message),
+                            );
+                    }
+                }
             }
         }
     }
@@ -272,18 +723,51 @@ where
     fn event(&self, event: &Event<'_>) {
         let name = event.metadata().name();
         println!("[{}] event: {};", self.name, name);
-        match self.expected.lock().unwrap().pop_front() {
+        {
+            let mut metrics = self.metrics.lock().unwrap();
+            *metrics
+                .events_by_level
+                .entry(*event.metadata().level())
+                .or_insert(0) += 1;
+            *metrics
+                .events_by_target
+                .entry(event.metadata().target())
+                .or_insert(0) += 1;
+        }
+        let observation_index = self.observation_index.fetch_add(1, Ordering::SeqCst);
+        let get_parent_name = || {
+            let stack = self.current.lock().unwrap();
+            let spans = self.spans.lock().unwrap();
+            event
+                .parent()
+                .and_then(|id| spans.get(id))
+                .or_else(|| stack.last().and_then(|id| spans.get(id)))
+                .map(|s| s.name.to_string())
+        };
+        for expectation in self.counted.lock().unwrap().iter() {
+            if expectation.event.matches(event, get_parent_name) {
+                let seen = expectation.seen.fetch_add(1, Ordering::SeqCst) + 1;
+                if let Some(max) = expectation.event.expected_count.max {
+                    assert!(
+                        seen <= max,
+                        "[{}] {} was expected at most {} time(s), but was recorded {} time(s)",
+                        self.name,
+                        expectation.event,
+                        max,
+                        seen
+                    );
+                }
+                if let Some((ref seq, ordinal)) = expectation.event.sequence {
+                    seq.record_match(ordinal, observation_index);
+                }
+            }
+        }
+        let matches =
+            |e: &Expect<'static>| matches!(e, Expect::Event(ev) if ev.matches(event, get_parent_name));
+        self.record_repeat_match(matches);
+        match self.take_expectation(matches) {
             None => {}
             Some(Expect::Event(mut expected)) => {
-                let get_parent_name = || {
-                    let stack = self.current.lock().unwrap();
-                    let spans = self.spans.lock().unwrap();
-                    event
-                        .parent()
-                        .and_then(|id| spans.get(id))
-                        .or_else(|| stack.last().and_then(|id| spans.get(id)))
-                        .map(|s| s.name.to_string())
-                };
                 expected.check(event, get_parent_name, &self.name);
             }
             Some(ex) => ex.bad(&self.name, format_args!("observed event {:#?}", event)),
@@ -298,7 +782,16 @@ where
                     "[{}] record_follows_from: {} (id={:?}) follows {} (id={:?})",
                     self.name, consequence_span.name, consequence_id, cause_span.name, cause_id,
                 );
-                match self.expected.lock().unwrap().pop_front() {
+                let matches = |e: &Expect<'static>| {
+                    matches!(
+                        e,
+                        Expect::FollowsFrom { consequence, cause }
+                            if consequence.name().map_or(true, |n| n == consequence_span.name)
+                                && cause.name().map_or(true, |n| n == cause_span.name)
+                    )
+                };
+                self.record_repeat_match(matches);
+                match self.take_expectation(matches) {
                     None => {}
                     Some(Expect::FollowsFrom {
                         consequence: ref expected_consequence,
@@ -325,8 +818,30 @@ where
 
     fn new_span(&self, span: &Attributes<'_>) -> Id {
         let meta = span.metadata();
-        let id = self.ids.fetch_add(1, Ordering::SeqCst);
-        let id = Id::from_u64(id as u64);
+        let mut expected_queue = self.expected.lock().unwrap();
+        let matches = |e: &Expect<'static>| {
+            matches!(e, Expect::NewSpan(s, _) if s.name().map_or(true, |n| n == meta.name()))
+        };
+        self.record_repeat_match(matches);
+        let pos = if self.unordered {
+            expected_queue.iter().position(matches)
+        } else {
+            match expected_queue.front() {
+                Some(e) if matches(e) => Some(0),
+                _ => None,
+            }
+        };
+        // A scripted `Id` (see `MockCollector::returning_id`) takes
+        // precedence over minting a fresh one, so tests can assert a
+        // deterministic `Id` is later passed to `enter`/`exit`/`record`.
+        let scripted_id = pos.and_then(|pos| match &expected_queue[pos] {
+            Expect::NewSpan(_, id) => id.clone(),
+            _ => None,
+        });
+        let id = scripted_id.unwrap_or_else(|| {
+            let id = self.ids.fetch_add(1, Ordering::SeqCst);
+            Id::from_u64(id as u64)
+        });
         println!(
             "[{}] new_span: name={:?}; target={:?}; id={:?};",
             self.name,
@@ -334,11 +849,9 @@ where
             meta.target(),
             id
         );
-        let mut expected = self.expected.lock().unwrap();
-        let was_expected = matches!(expected.front(), Some(Expect::NewSpan(_)));
         let mut spans = self.spans.lock().unwrap();
-        if was_expected {
-            if let Expect::NewSpan(mut expected) = expected.pop_front().unwrap() {
+        if let Some(pos) = pos {
+            if let Some(Expect::NewSpan(mut expected, _)) = expected_queue.remove(pos) {
                 let get_parent_name = || {
                     let stack = self.current.lock().unwrap();
                     span.parent()
@@ -357,6 +870,7 @@ where
                 meta,
             },
         );
+        self.metrics.lock().unwrap().spans_created += 1;
         id
     }
 
@@ -364,7 +878,11 @@ where
         let spans = self.spans.lock().unwrap();
         if let Some(span) = spans.get(id) {
             println!("[{}] enter: {}; id={:?};", self.name, span.name, id);
-            match self.expected.lock().unwrap().pop_front() {
+            let matches = |e: &Expect<'static>| {
+                matches!(e, Expect::Enter(s) if s.name().map_or(true, |n| n == span.name))
+            };
+            self.record_repeat_match(matches);
+            match self.take_expectation(matches) {
                 None => {}
                 Some(Expect::Enter(ref expected_span)) => {
                     if let Some(name) = expected_span.name() {
@@ -375,6 +893,9 @@ where
             }
         };
         self.current.lock().unwrap().push(id.clone());
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics.current_depth += 1;
+        metrics.max_depth = metrics.max_depth.max(metrics.current_depth);
     }
 
     fn exit(&self, id: &Id) {
@@ -389,7 +910,12 @@ where
             .get(id)
             .unwrap_or_else(|| panic!("[{}] no span for ID {:?}", self.name, id));
         println!("[{}] exit: {}; id={:?};", self.name, span.name, id);
-        match self.expected.lock().unwrap().pop_front() {
+        self.metrics.lock().unwrap().current_depth -= 1;
+        let matches = |e: &Expect<'static>| {
+            matches!(e, Expect::Exit(s) if s.name().map_or(true, |n| n == span.name))
+        };
+        self.record_repeat_match(matches);
+        match self.take_expectation(matches) {
             None => {}
             Some(Expect::Exit(ref expected_span)) => {
                 if let Some(name) = expected_span.name() {
@@ -422,21 +948,30 @@ where
         if name.is_none() {
             println!("[{}] clone_span: id={:?};", self.name, id);
         }
+        self.metrics.lock().unwrap().clones += 1;
         let mut expected = self.expected.lock().unwrap();
-        let was_expected = if let Some(Expect::CloneSpan(ref span)) = expected.front() {
-            assert_eq!(
-                name,
-                span.name(),
-                "[{}] expected to clone a span named {:?}",
-                self.name,
-                span.name()
-            );
-            true
+        let matches = |e: &Expect<'static>| {
+            matches!(e, Expect::CloneSpan(s) if s.name().map_or(true, |n| Some(n) == name))
+        };
+        self.record_repeat_match(matches);
+        let pos = if self.unordered {
+            expected.iter().position(matches)
         } else {
-            false
+            match expected.front() {
+                Some(e) if matches(e) => Some(0),
+                _ => None,
+            }
         };
-        if was_expected {
-            expected.pop_front();
+        if let Some(pos) = pos {
+            if let Some(Expect::CloneSpan(ref span)) = expected.remove(pos) {
+                assert_eq!(
+                    name,
+                    span.name(),
+                    "[{}] expected to clone a span named {:?}",
+                    self.name,
+                    span.name()
+                );
+            }
         }
         id.clone()
     }
@@ -462,26 +997,37 @@ where
         if name.is_none() {
             println!("[{}] drop_span: id={:?}", self.name, id);
         }
+        self.metrics.lock().unwrap().drops += 1;
         if let Ok(mut expected) = self.expected.try_lock() {
-            let was_expected = match expected.front() {
-                Some(Expect::DropSpan(ref span)) => {
-                    // Don't assert if this function was called while panicking,
-                    // as failing the assertion can cause a double panic.
-                    if !::std::thread::panicking() {
-                        assert_eq!(name, span.name());
-                    }
-                    true
+            let matches = |e: &Expect<'static>| {
+                matches!(e, Expect::DropSpan(s) if s.name().map_or(true, |n| Some(n) == name))
+                    || (is_event && matches!(e, Expect::Event(_)))
+            };
+            self.record_repeat_match(matches);
+            let pos = if self.unordered {
+                expected.iter().position(matches)
+            } else {
+                match expected.front() {
+                    Some(e) if matches(e) => Some(0),
+                    _ => None,
                 }
-                Some(Expect::Event(_)) => {
-                    if !::std::thread::panicking() {
-                        assert!(is_event, "[{}] expected an event", self.name);
+            };
+            if let Some(pos) = pos {
+                match expected.remove(pos) {
+                    Some(Expect::DropSpan(ref span)) => {
+                        // Don't assert if this function was called while panicking,
+                        // as failing the assertion can cause a double panic.
+                        if !::std::thread::panicking() {
+                            assert_eq!(name, span.name());
+                        }
                     }
-                    true
+                    Some(Expect::Event(_)) => {
+                        if !::std::thread::panicking() {
+                            assert!(is_event, "[{}] expected an event", self.name);
+                        }
+                    }
+                    _ => {}
                 }
-                _ => false,
-            };
-            if was_expected {
-                expected.pop_front();
             }
         }
     }
@@ -500,8 +1046,74 @@ where
 }
 
 impl<'a> MockHandle<'a> {
-    pub fn new(expected: Arc<Mutex<VecDeque<Expect<'a>>>>, name: String) -> Self {
-        Self(expected, name)
+    pub fn new(
+        expected: Arc<Mutex<VecDeque<Expect<'a>>>>,
+        counted: Arc<Mutex<Vec<CountExpectation<'a>>>>,
+        repeated: Arc<Mutex<Vec<RepeatExpectation<'a>>>>,
+        name: String,
+        metrics: Arc<Mutex<Tallies>>,
+    ) -> Self {
+        Self(expected, counted, repeated, name, metrics)
+    }
+
+    /// Returns the number of events observed at each `Level` that was
+    /// actually seen, as a set of named metrics (e.g. `"INFO"`, `"ERROR"`).
+    ///
+    /// Levels that were never observed are simply absent, rather than
+    /// reported with a value of `0`.
+    pub fn events_by_level(&self) -> Vec<Metric<'static, u64>> {
+        self.4
+            .lock()
+            .unwrap()
+            .events_by_level
+            .iter()
+            .map(|(level, count)| Metric::new(level.as_str(), *count))
+            .collect()
+    }
+
+    /// Convenience wrapper around [`MockHandle::events_by_level`] for a
+    /// single `Level`, returning `0` if no event at that level was ever
+    /// observed.
+    pub fn events_at_level(&self, level: Level) -> u64 {
+        self.4
+            .lock()
+            .unwrap()
+            .events_by_level
+            .get(&level)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of events observed for each target that was
+    /// actually seen, as a set of named metrics.
+    pub fn events_by_target(&self) -> Vec<Metric<'static, u64>> {
+        self.4
+            .lock()
+            .unwrap()
+            .events_by_target
+            .iter()
+            .map(|(target, count)| Metric::new(target, *count))
+            .collect()
+    }
+
+    /// Returns coarse span-lifecycle metrics:
+    ///
+    /// - `"spans_created"`: the total number of spans created via `new_span`.
+    /// - `"max_concurrent_depth"`: the highest number of spans ever entered
+    ///   at once.
+    /// - `"span_refs_leaked"`: how many span refs (one per `new_span`, plus
+    ///   one per `clone_span`) were never dropped, across all spans
+    ///   (non-zero means a ref was leaked).
+    pub fn span_metrics(&self) -> Vec<Metric<'static, u64>> {
+        let metrics = self.4.lock().unwrap();
+        vec![
+            Metric::new("spans_created", metrics.spans_created),
+            Metric::new("max_concurrent_depth", metrics.max_depth as u64),
+            Metric::new(
+                "span_refs_leaked",
+                (metrics.spans_created + metrics.clones).saturating_sub(metrics.drops),
+            ),
+        ]
     }
 
     pub fn assert_finished(&self) {
@@ -509,10 +1121,39 @@ impl<'a> MockHandle<'a> {
             assert!(
                 !expected.iter().any(|thing| thing != &Expect::Nothing),
                 "\n[{}] more notifications expected: {:#?}",
-                self.1,
+                self.3,
                 **expected
             );
         }
+        if let Ok(ref counted) = self.1.lock() {
+            for expectation in counted.iter() {
+                let seen = expectation.seen.load(Ordering::SeqCst);
+                assert!(
+                    seen >= expectation.event.expected_count.min,
+                    "\n[{}] expected {} at least {} time(s), but it was recorded {} time(s)",
+                    self.3,
+                    expectation.event,
+                    expectation.event.expected_count.min,
+                    seen
+                );
+                if let Some((ref seq, _)) = expectation.event.sequence {
+                    seq.assert_in_order(&self.3);
+                }
+            }
+        }
+        if let Ok(ref repeated) = self.2.lock() {
+            for expectation in repeated.iter() {
+                let seen = expectation.seen.load(Ordering::SeqCst);
+                assert!(
+                    seen >= expectation.cardinality.min,
+                    "\n[{}] expected {:?} at least {} time(s), but it was recorded {} time(s)",
+                    self.3,
+                    expectation.expect,
+                    expectation.cardinality.min,
+                    seen
+                );
+            }
+        }
     }
 }
 
@@ -548,11 +1189,11 @@ impl<'a> Expect<'a> {
                     name, e, name, what,
                 )
             }
-            Expect::Visit(e, fields) => panic!(
+            Expect::Visit(e, fields, _) => panic!(
                 "\n[{}] expected {} to record {:?}\n[{}] but instead {}",
                 name, e, fields, name, what,
             ),
-            Expect::NewSpan(e) => panic!(
+            Expect::NewSpan(e, _) => panic!(
                 "\n[{}] expected {}\n[{}] but instead {}",
                 name, e, name, what
             ),
@@ -563,3 +1204,96 @@ impl<'a> Expect<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::event::{msg, Sequence};
+
+    #[test]
+    fn counted_event_does_not_desync_the_ordered_queue() {
+        let (collector, handle) = mock()
+            .event(msg("counted").times(2))
+            .event(msg("final"))
+            .done()
+            .run_with_handle();
+
+        tracing::collect::with_default(collector, || {
+            tracing::info!("counted");
+            tracing::info!("counted");
+            tracing::info!("final");
+        });
+
+        handle.assert_finished();
+    }
+
+    #[test]
+    fn repeated_event_does_not_desync_the_ordered_queue() {
+        let (collector, handle) = mock()
+            .event(msg("first"))
+            .event(msg("repeated"))
+            .times(2)
+            .event(msg("last"))
+            .run_with_handle();
+
+        tracing::collect::with_default(collector, || {
+            tracing::info!("first");
+            tracing::info!("repeated");
+            tracing::info!("repeated");
+            tracing::info!("last");
+        });
+
+        handle.assert_finished();
+    }
+
+    #[test]
+    fn sequence_orders_a_subset_of_interleaved_events() {
+        let mut seq = Sequence::new();
+        let (collector, handle) = mock()
+            .event(msg("first").in_sequence(&mut seq))
+            .event(msg("unrelated"))
+            .event(msg("second").in_sequence(&mut seq))
+            .run_with_handle();
+
+        tracing::collect::with_default(collector, || {
+            tracing::info!("first");
+            tracing::info!("unrelated");
+            tracing::info!("second");
+        });
+
+        handle.assert_finished();
+    }
+
+    #[test]
+    #[should_panic]
+    fn sequence_violation_panics() {
+        let mut seq = Sequence::new();
+        let (collector, handle) = mock()
+            .event(msg("first").in_sequence(&mut seq))
+            .event(msg("second").in_sequence(&mut seq))
+            .run_with_handle();
+
+        tracing::collect::with_default(collector, || {
+            tracing::info!("second");
+            tracing::info!("first");
+        });
+
+        handle.assert_finished();
+    }
+
+    #[test]
+    fn unordered_mode_matches_out_of_order_events() {
+        let (collector, handle) = mock()
+            .event(msg("a"))
+            .event(msg("b"))
+            .unordered()
+            .run_with_handle();
+
+        tracing::collect::with_default(collector, || {
+            tracing::info!("b");
+            tracing::info!("a");
+        });
+
+        handle.assert_finished();
+    }
+}
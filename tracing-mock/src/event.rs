@@ -6,19 +6,152 @@ use crate::valuable::NamedValues_;
 use super::{metadata, span, Parent};
 
 use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A handle used to pin the relative order of a subset of mock event
+/// expectations.
+///
+/// Events attached to the same `Sequence` (via [`MockEvent::in_sequence`])
+/// must be observed in the order they were attached to it, even if other,
+/// unconstrained events are interleaved between them. This is checked when
+/// the mock collector finishes.
+#[derive(Clone, Default)]
+pub struct Sequence(Arc<Mutex<SequenceState>>);
+
+#[derive(Default)]
+struct SequenceState {
+    next_ordinal: usize,
+    /// `(ordinal, observation_index)` pairs recorded as events in this
+    /// sequence are matched. Monotonicity of `observation_index` with
+    /// respect to `ordinal` is checked in `assert_in_order`.
+    matches: Vec<(usize, usize)>,
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserves the next ordinal position in this sequence.
+    pub(crate) fn enroll(&self) -> usize {
+        let mut state = self.0.lock().unwrap();
+        let ordinal = state.next_ordinal;
+        state.next_ordinal += 1;
+        ordinal
+    }
+
+    /// Records that the event enrolled at `ordinal` was matched at
+    /// `observation_index`, the position of the observed event among all
+    /// events seen by the collector.
+    pub(crate) fn record_match(&self, ordinal: usize, observation_index: usize) {
+        self.0.lock().unwrap().matches.push((ordinal, observation_index));
+    }
+
+    /// Asserts that every recorded match happened in non-decreasing
+    /// `observation_index` order relative to its `ordinal`, i.e. that
+    /// events earlier in the sequence were never observed after events
+    /// later in the sequence.
+    pub(crate) fn assert_in_order(&self, collector_name: &str) {
+        let mut state = self.0.lock().unwrap();
+        state.matches.sort_by_key(|&(ordinal, _)| ordinal);
+        let mut last_observation_index = None;
+        for &(ordinal, observation_index) in state.matches.iter() {
+            if let Some(last) = last_observation_index {
+                assert!(
+                    observation_index >= last,
+                    "[{}] sequence violated: event at ordinal {} was observed \
+                     out of order (observation index {} came before index {})",
+                    collector_name,
+                    ordinal,
+                    observation_index,
+                    last
+                );
+            }
+            last_observation_index = Some(observation_index);
+        }
+    }
+}
+
+/// A per-field expectation for [`MockEvent::with_field`].
+///
+/// Unlike [`MockEvent::with_fields`], which asserts that the event's fields
+/// exactly equal a fixed [`NamedValues_`], a `FieldMatcher` only constrains
+/// a single named field, and can express things an exact-equality check
+/// can't, like "present with some value" or "satisfies this predicate".
+pub enum FieldMatcher<'a> {
+    /// The field's value must equal the given `Value`.
+    Eq(Value<'a>),
+    /// The field's value must satisfy the given predicate.
+    Predicate(Box<dyn Fn(&Value<'_>) -> bool + 'a>),
+    /// The field must be present, regardless of its value.
+    Present,
+    /// The field must not be present on the event.
+    Absent,
+}
+
+impl<'a> fmt::Debug for FieldMatcher<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldMatcher::Eq(value) => f.debug_tuple("Eq").field(value).finish(),
+            FieldMatcher::Predicate(_) => f.write_str("Predicate(..)"),
+            FieldMatcher::Present => f.write_str("Present"),
+            FieldMatcher::Absent => f.write_str("Absent"),
+        }
+    }
+}
+
+/// How many times a [`MockEvent`] must be recorded over the lifetime of a
+/// mock collector.
+///
+/// Defaults to "exactly once", matching the existing behavior of an
+/// unqualified `.event(...)` expectation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cardinality {
+    pub(crate) min: usize,
+    pub(crate) max: Option<usize>,
+}
+
+impl Default for Cardinality {
+    fn default() -> Self {
+        Cardinality {
+            min: 1,
+            max: Some(1),
+        }
+    }
+}
 
 /// A mock event.
 ///
 /// This is intended for use with the mock subscriber API in the
 /// `subscriber` module.
-#[derive(Default, Eq, PartialEq)]
+#[derive(Default)]
 pub struct MockEvent<'a> {
     pub fields: Option<NamedValues_<'a>>,
     pub(crate) parent: Option<Parent>,
     in_spans: Vec<span::MockSpan>,
     metadata: metadata::Expect,
+    field_matchers: Vec<(String, FieldMatcher<'a>)>,
+    pub(crate) expected_count: Cardinality,
+    pub(crate) sequence: Option<(Sequence, usize)>,
 }
 
+// `FieldMatcher::Predicate` holds a `Box<dyn Fn>`, and `Sequence` is an
+// opaque shared handle, so both are excluded here for the same reason
+// `Expect`'s `PartialEq` impl (in `collector.rs`) excludes its own
+// predicate-bearing fields: they're runtime checks performed in
+// `check`/the collector, not values compared against another `MockEvent`.
+impl<'a> PartialEq for MockEvent<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields
+            && self.parent == other.parent
+            && self.in_spans == other.in_spans
+            && self.metadata == other.metadata
+            && self.expected_count == other.expected_count
+    }
+}
+
+impl<'a> Eq for MockEvent<'a> {}
+
 pub fn mock<'a>() -> MockEvent<'a> {
     MockEvent {
         ..Default::default()
@@ -59,6 +192,16 @@ impl<'a> MockEvent<'a> {
         }
     }
 
+    /// Adds an expectation that the field named `name` matches `matcher`.
+    ///
+    /// Multiple calls accumulate: each field gets its own independent
+    /// expectation, evaluated in `check` alongside any exact-match fields
+    /// set via `with_fields`.
+    pub fn with_field(mut self, name: impl Into<String>, matcher: FieldMatcher<'a>) -> Self {
+        self.field_matchers.push((name.into(), matcher));
+        self
+    }
+
     pub fn at_level(self, level: tracing::Level) -> Self {
         Self {
             metadata: metadata::Expect {
@@ -82,6 +225,120 @@ impl<'a> MockEvent<'a> {
         }
     }
 
+    /// Expects this event to be recorded exactly `n` times.
+    pub fn times(self, n: usize) -> Self {
+        Self {
+            expected_count: Cardinality {
+                min: n,
+                max: Some(n),
+            },
+            ..self
+        }
+    }
+
+    /// Expects this event to never be recorded.
+    pub fn never(self) -> Self {
+        self.times(0)
+    }
+
+    /// Expects this event to be recorded at least `n` times.
+    pub fn at_least(self, n: usize) -> Self {
+        Self {
+            expected_count: Cardinality { min: n, max: None },
+            ..self
+        }
+    }
+
+    /// Expects this event to be recorded at most `n` times.
+    pub fn at_most(self, n: usize) -> Self {
+        Self {
+            expected_count: Cardinality {
+                min: 0,
+                max: Some(n),
+            },
+            ..self
+        }
+    }
+
+    /// Returns `true` if `event` satisfies this expectation's metadata,
+    /// fields, field matchers, and parent, without panicking on a mismatch.
+    ///
+    /// This backs call-count expectations (`times`/`never`/`at_least`/
+    /// `at_most`), which need to test every observed event against a single
+    /// expectation rather than asserting that the very next event matches.
+    pub(crate) fn matches(
+        &self,
+        event: &tracing::Event<'_>,
+        get_parent_name: impl Fn() -> Option<String>,
+    ) -> bool {
+        let meta = event.metadata();
+        if !meta.is_event() {
+            return false;
+        }
+        if let Some(ref name) = self.metadata.name {
+            if meta.name() != name {
+                return false;
+            }
+        }
+        if let Some(ref target) = self.metadata.target {
+            if meta.target() != target {
+                return false;
+            }
+        }
+        if let Some(level) = self.metadata.level {
+            if *meta.level() != level {
+                return false;
+            }
+        }
+        if let Some(expected_fields) = self.fields.clone() {
+            if expected_fields != NamedValues_(*event.fields()) {
+                return false;
+            }
+        }
+        let fields = *event.fields();
+        for (field_name, matcher) in &self.field_matchers {
+            let actual = fields.get_by_name(field_name);
+            let matched = match matcher {
+                FieldMatcher::Eq(expected) => actual
+                    .map(|actual| crate::valuable::values_equal(expected.clone(), actual))
+                    .unwrap_or(false),
+                FieldMatcher::Predicate(predicate) => {
+                    actual.map(|actual| predicate(&actual)).unwrap_or(false)
+                }
+                FieldMatcher::Present => actual.is_some(),
+                FieldMatcher::Absent => actual.is_none(),
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(ref expected_parent) = self.parent {
+            let actual_parent = get_parent_name();
+            let parent_matches = match expected_parent {
+                Parent::Explicit(name) => actual_parent.as_deref() == Some(name.as_str()),
+                Parent::ExplicitRoot => actual_parent.is_none(),
+                _ => true,
+            };
+            if !parent_matches {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Pins this event's relative order within `seq`.
+    ///
+    /// Events attached to the same `Sequence` must be observed in the order
+    /// they were attached, even if other, unconstrained events are
+    /// interleaved between them.
+    pub fn in_sequence(self, seq: &mut Sequence) -> Self {
+        let ordinal = seq.enroll();
+        Self {
+            sequence: Some((seq.clone(), ordinal)),
+            ..self
+        }
+    }
+
     pub fn with_explicit_parent(self, parent: Option<&str>) -> MockEvent {
         let parent = match parent {
             Some(name) => Parent::Explicit(name.into()),
@@ -110,11 +367,62 @@ impl<'a> MockEvent<'a> {
             self,
             event
         );
-        if let Some(expected_fields) = self.fields {
+        if let Some(expected_fields) = self.fields.clone() {
             let named_values = NamedValues_(*event.fields());
             assert_eq!(expected_fields, named_values);
         }
 
+        let fields = *event.fields();
+        for (field_name, matcher) in &self.field_matchers {
+            let actual = fields.get_by_name(field_name);
+            match matcher {
+                FieldMatcher::Eq(expected) => match actual {
+                    Some(actual) => assert!(
+                        crate::valuable::values_equal(expected.clone(), actual),
+                        "[{}] event \"{}\": expected field `{}` to equal {:?}, but it was {:?}",
+                        collector_name,
+                        name,
+                        field_name,
+                        expected,
+                        actual
+                    ),
+                    None => panic!(
+                        "[{}] event \"{}\": expected field `{}` to be present and equal {:?}, but it was absent",
+                        collector_name, name, field_name, expected
+                    ),
+                },
+                FieldMatcher::Predicate(predicate) => match actual {
+                    Some(actual) => assert!(
+                        predicate(&actual),
+                        "[{}] event \"{}\": field `{}` ({:?}) did not satisfy the expected predicate",
+                        collector_name,
+                        name,
+                        field_name,
+                        actual
+                    ),
+                    None => panic!(
+                        "[{}] event \"{}\": expected field `{}` to be present, but it was absent",
+                        collector_name, name, field_name
+                    ),
+                },
+                FieldMatcher::Present => assert!(
+                    actual.is_some(),
+                    "[{}] event \"{}\": expected field `{}` to be present, but it was absent",
+                    collector_name,
+                    name,
+                    field_name
+                ),
+                FieldMatcher::Absent => assert!(
+                    actual.is_none(),
+                    "[{}] event \"{}\": expected field `{}` to be absent, but it was {:?}",
+                    collector_name,
+                    name,
+                    field_name,
+                    actual
+                ),
+            }
+        }
+
         if let Some(ref expected_parent) = self.parent {
             let actual_parent = get_parent_name();
             expected_parent.check_parent_name(
@@ -164,6 +472,10 @@ impl<'a> fmt::Debug for MockEvent<'a> {
             s.field("fields", fields);
         }
 
+        if !self.field_matchers.is_empty() {
+            s.field("field_matchers", &self.field_matchers);
+        }
+
         if let Some(ref parent) = self.parent {
             s.field("parent", &format_args!("{:?}", parent));
         }
@@ -172,6 +484,36 @@ impl<'a> fmt::Debug for MockEvent<'a> {
             s.field("in_spans", &self.in_spans);
         }
 
+        if let Some((_, ordinal)) = self.sequence {
+            s.field("sequence_ordinal", &ordinal);
+        }
+
         s.finish()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sequence_accepts_non_decreasing_observations() {
+        let seq = Sequence::new();
+        let first = seq.enroll();
+        let second = seq.enroll();
+        seq.record_match(first, 0);
+        seq.record_match(second, 1);
+        seq.assert_in_order("test");
+    }
+
+    #[test]
+    #[should_panic]
+    fn sequence_rejects_out_of_order_observations() {
+        let seq = Sequence::new();
+        let first = seq.enroll();
+        let second = seq.enroll();
+        seq.record_match(first, 1);
+        seq.record_match(second, 0);
+        seq.assert_in_order("test");
+    }
+}
@@ -1,6 +1,7 @@
-use std::sync::{Mutex, Arc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
-use valuable::{NamedValues, Value};
+use valuable::{NamedValues, Valuable, Value, Visit};
 
 // TODO: Set visibility level to `crate`
 #[derive(Debug, Default, Clone)]
@@ -22,9 +23,303 @@ impl<'a> NamedValues_<'a> {
 }
 
 impl<'a> PartialEq for NamedValues_<'a> {
-    fn eq(&self, _other: &Self) -> bool {
-        todo!();
+    fn eq(&self, other: &Self) -> bool {
+        let ours = Owned::from_named_values(&self.0.lock().unwrap());
+        let theirs = Owned::from_named_values(&other.0.lock().unwrap());
+        ours == theirs
     }
 }
 
-impl<'a> Eq for NamedValues_<'a> {}
\ No newline at end of file
+impl<'a> Eq for NamedValues_<'a> {}
+
+/// An owned, structurally comparable snapshot of a [`valuable::Value`].
+///
+/// `Value<'_>` borrows from whatever produced it, and `Visit`'s callbacks
+/// hand back values with an unconstrained lifetime, so they can't be held
+/// onto past the `visit` call that produced them. Converting eagerly to
+/// this owned form lets us walk two `Value` trees and compare them
+/// field-by-field (treating named fields as an unordered set) without
+/// fighting the borrow checker.
+#[derive(Debug, Clone)]
+enum Owned {
+    Bool(bool),
+    Char(char),
+    F32(f32),
+    F64(f64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    String(String),
+    Unit,
+    Error(String),
+    List(Vec<Owned>),
+    Map(Vec<(Owned, Owned)>),
+    Struct(HashMap<String, Owned>),
+    Enum {
+        variant: String,
+        fields: HashMap<String, Owned>,
+    },
+    /// A value kind this comparison doesn't understand (any `Value` variant
+    /// not listed in [`Owned::from_value_at`]), or a value nested deeper than
+    /// [`MAX_VALUE_DEPTH`]. Never equal to anything, including another
+    /// `Unsupported`.
+    Unsupported,
+}
+
+impl PartialEq for Owned {
+    fn eq(&self, other: &Self) -> bool {
+        use Owned::*;
+        match (self, other) {
+            (Bool(a), Bool(b)) => a == b,
+            (Char(a), Char(b)) => a == b,
+            (F32(a), F32(b)) => a == b,
+            (F64(a), F64(b)) => a == b,
+            (I8(a), I8(b)) => a == b,
+            (I16(a), I16(b)) => a == b,
+            (I32(a), I32(b)) => a == b,
+            (I64(a), I64(b)) => a == b,
+            (I128(a), I128(b)) => a == b,
+            (Isize(a), Isize(b)) => a == b,
+            (U8(a), U8(b)) => a == b,
+            (U16(a), U16(b)) => a == b,
+            (U32(a), U32(b)) => a == b,
+            (U64(a), U64(b)) => a == b,
+            (U128(a), U128(b)) => a == b,
+            (Usize(a), Usize(b)) => a == b,
+            (String(a), String(b)) => a == b,
+            (Unit, Unit) => true,
+            (Error(a), Error(b)) => a == b,
+            (List(a), List(b)) => a == b,
+            (Struct(a), Struct(b)) => a == b,
+            (
+                Enum {
+                    variant: va,
+                    fields: fa,
+                },
+                Enum {
+                    variant: vb,
+                    fields: fb,
+                },
+            ) => va == vb && fa == fb,
+            (Map(a), Map(b)) => entries_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Compares two sets of map entries without regard to order.
+fn entries_eq(a: &[(Owned, Owned)], b: &[(Owned, Owned)]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut remaining: Vec<&(Owned, Owned)> = b.iter().collect();
+    for entry in a {
+        let Some(pos) = remaining.iter().position(|other| *other == entry) else {
+            return false;
+        };
+        remaining.swap_remove(pos);
+    }
+    true
+}
+
+/// Caps how deep [`Owned::from_value`] will recurse into a nested
+/// `Listable`/`Mappable`/`Structable`/`Enumerable`/`Tuplable` value, as a
+/// guard against cycles (a `valuable::Valuable` impl has nothing stopping it
+/// from visiting itself).  Past this depth, a value is treated as
+/// [`Owned::Unsupported`] rather than recursed into further.
+const MAX_VALUE_DEPTH: usize = 16;
+
+impl Owned {
+    fn from_named_values(named_values: &NamedValues<'_>) -> Self {
+        Self::from_named_values_at(named_values, 0)
+    }
+
+    fn from_named_values_at(named_values: &NamedValues<'_>, depth: usize) -> Self {
+        let mut fields = HashMap::new();
+        for (field, value) in named_values.iter() {
+            fields.insert(field.name().to_string(), Owned::from_value_at(value, depth));
+        }
+        Owned::Struct(fields)
+    }
+
+    fn from_value(value: Value<'_>) -> Self {
+        Self::from_value_at(value, 0)
+    }
+
+    fn from_value_at(value: Value<'_>, depth: usize) -> Self {
+        if depth >= MAX_VALUE_DEPTH {
+            return Owned::Unsupported;
+        }
+        match value {
+            Value::Bool(v) => Owned::Bool(v),
+            Value::Char(v) => Owned::Char(v),
+            Value::F32(v) => Owned::F32(v),
+            Value::F64(v) => Owned::F64(v),
+            Value::I8(v) => Owned::I8(v),
+            Value::I16(v) => Owned::I16(v),
+            Value::I32(v) => Owned::I32(v),
+            Value::I64(v) => Owned::I64(v),
+            Value::I128(v) => Owned::I128(v),
+            Value::Isize(v) => Owned::Isize(v),
+            Value::U8(v) => Owned::U8(v),
+            Value::U16(v) => Owned::U16(v),
+            Value::U32(v) => Owned::U32(v),
+            Value::U64(v) => Owned::U64(v),
+            Value::U128(v) => Owned::U128(v),
+            Value::Usize(v) => Owned::Usize(v),
+            Value::String(v) => Owned::String(v.to_string()),
+            Value::Unit => Owned::Unit,
+            Value::Error(e) => Owned::Error(e.to_string()),
+            Value::Listable(v) => {
+                let mut visitor = Collector::at_depth(depth + 1);
+                v.visit(&mut visitor);
+                Owned::List(visitor.list)
+            }
+            Value::Mappable(v) => {
+                let mut visitor = Collector::at_depth(depth + 1);
+                v.visit(&mut visitor);
+                Owned::Map(visitor.entries)
+            }
+            Value::Structable(v) => {
+                let mut visitor = Collector::at_depth(depth + 1);
+                v.visit(&mut visitor);
+                Owned::Struct(visitor.named)
+            }
+            Value::Enumerable(v) => {
+                let mut visitor = Collector::at_depth(depth + 1);
+                v.visit(&mut visitor);
+                Owned::Enum {
+                    variant: v.variant().name().to_string(),
+                    fields: visitor.named,
+                }
+            }
+            Value::Tuplable(v) => {
+                let mut visitor = Collector::at_depth(depth + 1);
+                v.visit(&mut visitor);
+                Owned::List(visitor.list)
+            }
+            _ => Owned::Unsupported,
+        }
+    }
+}
+
+/// Structurally compares two `valuable::Value`s, recursing into nested
+/// `Listable`/`Mappable`/`Structable`/`Enumerable`/`Tuplable` values. Used
+/// by [`crate::collector`] to check recorded span fields against expected
+/// ones for value kinds that don't have a primitive, directly comparable
+/// representation.
+pub(crate) fn values_equal(a: Value<'_>, b: Value<'_>) -> bool {
+    Owned::from_value(a) == Owned::from_value(b)
+}
+
+/// Walks a single level of a `valuable` value tree, recursing into
+/// [`Owned::from_value_at`] for each named/unnamed field, list item, or map
+/// entry it encounters, one level deeper than the value that produced this
+/// visitor.
+#[derive(Default)]
+struct Collector {
+    depth: usize,
+    named: HashMap<String, Owned>,
+    list: Vec<Owned>,
+    entries: Vec<(Owned, Owned)>,
+}
+
+impl Collector {
+    fn at_depth(depth: usize) -> Self {
+        Self {
+            depth,
+            ..Default::default()
+        }
+    }
+}
+
+impl Visit for Collector {
+    fn visit_value(&mut self, value: Value<'_>) {
+        self.list.push(Owned::from_value_at(value, self.depth));
+    }
+
+    fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+        for (field, value) in named_values.iter() {
+            self.named
+                .insert(field.name().to_string(), Owned::from_value_at(value, self.depth));
+        }
+    }
+
+    fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+        for value in values {
+            self.list.push(Owned::from_value_at(*value, self.depth));
+        }
+    }
+
+    fn visit_entry(&mut self, key: Value<'_>, value: Value<'_>) {
+        self.entries.push((
+            Owned::from_value_at(key, self.depth),
+            Owned::from_value_at(value, self.depth),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use valuable::NamedField;
+
+    #[test]
+    fn primitives_compare_by_value() {
+        assert!(values_equal(Value::U64(4), Value::U64(4)));
+        assert!(!values_equal(Value::U64(4), Value::U64(5)));
+        assert!(!values_equal(Value::U64(4), Value::I64(4)));
+    }
+
+    #[test]
+    fn structs_compare_order_independent_of_field_order() {
+        #[derive(Valuable)]
+        struct Fields {
+            foo: u32,
+            bar: u32,
+        }
+
+        let a = Fields { foo: 1, bar: 2 };
+        let b = Fields { foo: 1, bar: 2 };
+        assert!(values_equal(a.as_value(), b.as_value()));
+
+        #[derive(Valuable)]
+        struct Reordered {
+            bar: u32,
+            foo: u32,
+        }
+
+        let reordered = Reordered { bar: 2, foo: 1 };
+        assert!(values_equal(a.as_value(), reordered.as_value()));
+
+        let different = Fields { foo: 1, bar: 3 };
+        assert!(!values_equal(a.as_value(), different.as_value()));
+    }
+
+    #[test]
+    fn maps_compare_order_independent_of_entry_order() {
+        let a: HashMap<&str, u32> = [("foo", 1), ("bar", 2)].into_iter().collect();
+        let b: HashMap<&str, u32> = [("bar", 2), ("foo", 1)].into_iter().collect();
+        assert!(values_equal(a.as_value(), b.as_value()));
+    }
+
+    #[test]
+    fn named_values_compare_structurally() {
+        let fields = [NamedField::new("foo"), NamedField::new("bar")];
+        let a = NamedValues_::new(NamedValues::new(&fields, &[Value::U32(1), Value::U32(2)]));
+        let b = NamedValues_::new(NamedValues::new(&fields, &[Value::U32(1), Value::U32(2)]));
+        assert_eq!(a, b);
+
+        let c = NamedValues_::new(NamedValues::new(&fields, &[Value::U32(1), Value::U32(3)]));
+        assert_ne!(a, c);
+    }
+}
@@ -1,30 +1,122 @@
 use std::fmt;
+use std::sync::Arc;
+
+use opentelemetry::{
+    metrics::{Descriptor, InstrumentKind, Number, NumberKind},
+    sdk::export::metrics::{Aggregator, AggregatorSelector},
+    sdk::metrics::aggregators::{HistogramAggregator, SumAggregator},
+};
 use tracing::field::Visit;
 use tracing_core::Field;
 
-#[derive(Default, Debug)]
-pub(crate) struct Metric<T> {
+/// The default OTel/Prometheus latency bucket boundaries (seconds), used for
+/// any `ValueRecorder` instrument unless the caller configures its own via
+/// [`MetricAggregatorSelector::new`].
+pub(crate) const DEFAULT_HISTOGRAM_BOUNDARIES: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// A metric parsed from a single event field, following this crate's naming
+/// convention: a field name's prefix selects the [`InstrumentKind`], and the
+/// Rust type the value was recorded as selects the [`NumberKind`].
+#[derive(Debug)]
+pub(crate) struct Metric {
     pub(crate) name: String,
-    pub(crate) value: T,
+    pub(crate) instrument_kind: InstrumentKind,
+    pub(crate) number_kind: NumberKind,
+    pub(crate) value: Number,
 }
 
-pub(crate) struct MetricVisitor<'a>(pub(crate) &'a mut Metric<u64>);
+impl Default for Metric {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            instrument_kind: InstrumentKind::Counter,
+            number_kind: NumberKind::U64,
+            value: Number::from(0_u64),
+        }
+    }
+}
 
-impl<'a> Visit for MetricVisitor<'a> {
-    fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {
-        // Do nothing
+/// Maps a field name's prefix to the `InstrumentKind` it should be recorded
+/// as, or `None` if the field isn't a recognized metric field at all.
+fn instrument_kind_for(field_name: &str) -> Option<InstrumentKind> {
+    if field_name.starts_with("MONOTONIC_COUNTER_") {
+        Some(InstrumentKind::Counter)
+    } else if field_name.starts_with("COUNTER_") || field_name.starts_with("UPDOWN_COUNTER_") {
+        Some(InstrumentKind::UpDownCounter)
+    } else if field_name.starts_with("VALUE_") || field_name.starts_with("HISTOGRAM_") {
+        Some(InstrumentKind::ValueRecorder)
+    } else {
+        None
     }
+}
 
-    // fn record_str(&mut self, field: &Field, value: &str) {
-    //     if field.name() == "metric.name" {
-    //         self.0.name = value.to_string().into();
-    //     }
-    // }
+pub(crate) struct MetricVisitor<'a>(pub(crate) &'a mut Metric);
 
-    fn record_u64(&mut self, field: &Field, value: u64) {
-        if field.name().starts_with("METRIC_") {
+impl<'a> MetricVisitor<'a> {
+    fn record(&mut self, field: &Field, number_kind: NumberKind, value: Number) {
+        if let Some(instrument_kind) = instrument_kind_for(field.name()) {
             self.0.name = field.name().to_string();
+            self.0.instrument_kind = instrument_kind;
+            self.0.number_kind = number_kind;
             self.0.value = value;
         }
     }
 }
+
+impl<'a> Visit for MetricVisitor<'a> {
+    fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {
+        // Do nothing; metric fields are always recorded as one of the
+        // typed `record_*` methods below.
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, NumberKind::U64, Number::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, NumberKind::I64, Number::from(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, NumberKind::F64, Number::from(value));
+    }
+}
+
+/// Chooses the [`Aggregator`] used to accumulate each instrument's
+/// measurements: a bounded-memory [`HistogramAggregator`] for `VALUE_`/
+/// `HISTOGRAM_` fields (see [`instrument_kind_for`]), and the usual
+/// [`SumAggregator`] for everything else.
+///
+/// Unlike `selectors::simple::Selector::Exact`, which keeps every raw point
+/// forever, this gives `ValueRecorder` instruments bounded memory use, at
+/// the cost of only approximate quantiles.
+pub(crate) struct MetricAggregatorSelector {
+    histogram_boundaries: Arc<Vec<f64>>,
+}
+
+impl MetricAggregatorSelector {
+    /// Returns a selector that buckets `ValueRecorder` instruments using
+    /// `histogram_boundaries`, falling back to [`DEFAULT_HISTOGRAM_BOUNDARIES`]
+    /// when none are given.
+    pub(crate) fn new(histogram_boundaries: Option<Vec<f64>>) -> Self {
+        Self {
+            histogram_boundaries: Arc::new(
+                histogram_boundaries
+                    .unwrap_or_else(|| DEFAULT_HISTOGRAM_BOUNDARIES.to_vec()),
+            ),
+        }
+    }
+}
+
+impl AggregatorSelector for MetricAggregatorSelector {
+    fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
+        match descriptor.instrument_kind() {
+            InstrumentKind::ValueRecorder => Some(Arc::new(HistogramAggregator::new(
+                &self.histogram_boundaries,
+            ))),
+            _ => Some(Arc::new(SumAggregator::default())),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! A pull-based alternative to the push-mode metrics subscriber: instead of
+//! forwarding every observation to a user-supplied [`MetricsExporter`] as it
+//! happens, metrics accumulate in a `controllers::pull` aggregator and are
+//! rendered on demand, in Prometheus text exposition format, by an embedded
+//! HTTP server.
+//!
+//! [`pull_subscriber`] is the entry point: it builds the `pull` controller,
+//! wraps it in an `OpenTelemetryMetricsSubscriber` the same way the
+//! push-mode constructor wraps a `push` controller, and starts a
+//! [`ScrapeServer`] rendering that controller's [`CheckpointSet`] (via
+//! [`encode`]) on every `GET /metrics`.
+//!
+//! Gated behind the `prometheus` feature so the HTTP server isn't forced on
+//! users who only want push-mode export.
+#![cfg(feature = "prometheus")]
+
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write as _};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::thread;
+
+use opentelemetry::{
+    metrics::InstrumentKind,
+    sdk::export::metrics::{Aggregator, CheckpointSet, ExportKindSelector},
+    sdk::metrics::{
+        aggregators::{HistogramAggregator, SumAggregator},
+        controllers,
+    },
+};
+
+use crate::metric::MetricAggregatorSelector;
+use crate::OpenTelemetryMetricsSubscriber;
+
+/// Builds a pull-mode [`OpenTelemetryMetricsSubscriber`] and starts serving
+/// its aggregated state for Prometheus to scrape.
+///
+/// Binds `addr` immediately and serves `GET /metrics` (and `GET /health`)
+/// from a background thread via [`ScrapeServer`]; every scrape renders the
+/// controller's current [`CheckpointSet`] through [`encode`]. Unlike the
+/// push-mode constructor, there's no exporter and no export interval to
+/// configure: metrics simply accumulate in the controller until a scrape
+/// asks for them.
+///
+/// `histogram_boundaries` configures the bucket boundaries (seconds) used
+/// for every `ValueRecorder` instrument via [`MetricAggregatorSelector`];
+/// pass `None` to use [`crate::metric::DEFAULT_HISTOGRAM_BOUNDARIES`].
+pub fn pull_subscriber(
+    addr: impl ToSocketAddrs,
+    histogram_boundaries: Option<Vec<f64>>,
+) -> std::io::Result<(OpenTelemetryMetricsSubscriber, ScrapeServer)> {
+    let controller = controllers::pull(
+        MetricAggregatorSelector::new(histogram_boundaries),
+        ExportKindSelector::Cumulative,
+    )
+    .build();
+    let checkpointer = controller.clone();
+    let server = ScrapeServer::spawn(addr, move || encode(&mut checkpointer.clone()))?;
+    Ok((OpenTelemetryMetricsSubscriber::new(controller), server))
+}
+
+/// Renders every record in `checkpoint_set` as Prometheus text exposition
+/// format.
+///
+/// `InstrumentKind::Counter` becomes a `counter`, `UpDownCounter` becomes a
+/// `gauge`, and `ValueRecorder` becomes a `histogram` (`_bucket`/`_sum`/
+/// `_count` lines), reading the aggregated value via `SumAggregator::sum()`
+/// or the cumulative bucket counts kept by `HistogramAggregator`, exactly as
+/// `MetricsExporter::export` implementations do.
+pub(crate) fn encode(checkpoint_set: &mut dyn CheckpointSet) -> String {
+    let mut out = String::new();
+    let _ = checkpoint_set.try_for_each(&ExportKindSelector::Cumulative, &mut |record| {
+        let descriptor = record.descriptor();
+        let name = sanitize(descriptor.name());
+        let number_kind = descriptor.number_kind();
+        match descriptor.instrument_kind() {
+            InstrumentKind::Counter => {
+                if let Some(sum) = record
+                    .aggregator()
+                    .and_then(|agg| agg.as_any().downcast_ref::<SumAggregator>())
+                    .and_then(|agg| agg.sum().ok())
+                {
+                    let _ = writeln!(out, "# TYPE {name} counter");
+                    let _ = writeln!(out, "{name} {}", sum.to_f64(number_kind));
+                }
+            }
+            InstrumentKind::UpDownCounter => {
+                if let Some(sum) = record
+                    .aggregator()
+                    .and_then(|agg| agg.as_any().downcast_ref::<SumAggregator>())
+                    .and_then(|agg| agg.sum().ok())
+                {
+                    let _ = writeln!(out, "# TYPE {name} gauge");
+                    let _ = writeln!(out, "{name} {}", sum.to_f64(number_kind));
+                }
+            }
+            InstrumentKind::ValueRecorder => {
+                if let Some(histogram) = record
+                    .aggregator()
+                    .and_then(|agg| agg.as_any().downcast_ref::<HistogramAggregator>())
+                {
+                    if let (Ok(buckets), Ok(sum), Ok(count)) =
+                        (histogram.histogram(), histogram.sum(), histogram.count())
+                    {
+                        let sum = sum.to_f64(number_kind);
+                        let _ = writeln!(out, "# TYPE {name} histogram");
+                        let mut cumulative = 0u64;
+                        for (bound, bucket_count) in
+                            buckets.boundaries().iter().zip(buckets.counts().iter())
+                        {
+                            cumulative += *bucket_count as u64;
+                            let _ = writeln!(out, "{name}_bucket{{le=\"{bound}\"}} {cumulative}");
+                        }
+                        let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {count}");
+                        let _ = writeln!(out, "{name}_sum {sum}");
+                        let _ = writeln!(out, "{name}_count {count}");
+                    }
+                }
+            }
+            _ => {}
+        }
+        opentelemetry::metrics::Result::Ok(())
+    });
+    out
+}
+
+/// Prometheus metric names may only contain `[a-zA-Z0-9_:]`; anything else
+/// in a field name (e.g. the `.` in a target-qualified name) is replaced
+/// with `_`.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == ':' { c } else { '_' })
+        .collect()
+}
+
+/// An embedded HTTP server exposing `GET /metrics` (Prometheus text
+/// exposition format, rendered from `render` on every scrape) and
+/// `GET /health` (always `200 OK`, so the same server doubles as a
+/// liveness probe).
+///
+/// Serves requests on a single background thread; this is a scrape target; it
+/// isn't meant to handle concurrent load.
+pub struct ScrapeServer {
+    local_addr: std::net::SocketAddr,
+    _handle: thread::JoinHandle<()>,
+}
+
+impl ScrapeServer {
+    /// Binds `addr` and starts serving `/metrics` and `/health` in a
+    /// background thread, rendering each scrape from `render`.
+    pub fn spawn(
+        addr: impl ToSocketAddrs,
+        render: impl Fn() -> String + Send + Sync + 'static,
+    ) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+        let render = Arc::new(render);
+        let handle = thread::Builder::new()
+            .name("tracing-opentelemetry-prometheus-scrape".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else { continue };
+                    let render = render.clone();
+                    if let Err(_err) = handle_connection(stream, &render) {
+                        // Scraper disconnected early or sent a malformed
+                        // request; nothing useful to do but move on to the
+                        // next connection.
+                    }
+                }
+            })?;
+        Ok(Self {
+            local_addr,
+            _handle: handle,
+        })
+    }
+
+    /// The address this server actually bound to (useful when `addr` was
+    /// passed with port `0`).
+    pub fn local_addr(&self) -> std::net::SocketAddr {
+        self.local_addr
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    render: &(impl Fn() -> String + Send + Sync),
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let body = if request_line.starts_with("GET /metrics ") {
+        Some(render())
+    } else if request_line.starts_with("GET /health ") {
+        Some(String::new())
+    } else {
+        None
+    };
+
+    match body {
+        Some(body) => {
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nContent-Type: text/plain; version=0.0.4\r\n\r\n{}",
+                body.len(),
+                body
+            )
+        }
+        None => write!(stream, "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n"),
+    }
+}
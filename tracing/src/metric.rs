@@ -1,14 +1,20 @@
-#[derive(Default)]
+//! A named numeric reading, used by coarse-grained metrics collectors (such
+//! as `tracing_mock::collector::MockHandle`'s tally accessors) to report a
+//! value without needing a bespoke struct per measurement.
+
+/// A single named metric, pairing a label (a level's name, an event's
+/// target, a counter's name, ...) with its current value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct Metric<'a, T> {
+    /// The name of this metric.
     pub name: &'a str,
+    /// The metric's current value.
     pub value: T,
 }
 
 impl<'a, T> Metric<'a, T> {
+    /// Returns a new named metric with the given value.
     pub fn new(name: &'a str, value: T) -> Self {
-        Metric {
-            name,
-            value,
-        }
+        Metric { name, value }
     }
-}
\ No newline at end of file
+}